@@ -0,0 +1,85 @@
+use crate::jwk_auth::JwkAuth;
+use crate::verifier::VerifyError;
+use axum::extract::{FromRef, FromRequestParts};
+use axum::http::request::Parts;
+use axum::http::StatusCode;
+use axum::RequestPartsExt;
+use axum_extra::headers::authorization::Bearer;
+use axum_extra::headers::Authorization;
+use axum_extra::TypedHeader;
+use serde::de::DeserializeOwned;
+
+/// Extracts and verifies the `Authorization: Bearer` token of a request.
+/// Requires the application state to provide a [`JwkAuth`] via [`FromRef`].
+pub struct Claims<C>(pub C);
+
+#[async_trait::async_trait]
+impl<C, S> FromRequestParts<S> for Claims<C>
+where
+    C: DeserializeOwned + Send + Sync + 'static,
+    JwkAuth: FromRef<S>,
+    S: Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let TypedHeader(Authorization(bearer)) = parts
+            .extract::<TypedHeader<Authorization<Bearer>>>()
+            .await
+            .map_err(|_| (StatusCode::UNAUTHORIZED, "missing bearer token"))?;
+
+        let jwk_auth = JwkAuth::from_ref(state);
+        let token_data = jwk_auth
+            .verify::<C>(bearer.token())
+            .await
+            .map_err(verify_error_to_rejection)?;
+
+        Ok(Claims(token_data.claims))
+    }
+}
+
+fn verify_error_to_rejection(err: VerifyError) -> (StatusCode, &'static str) {
+    match err {
+        VerifyError::MissingKid | VerifyError::MalformedToken => {
+            (StatusCode::UNAUTHORIZED, "malformed token")
+        }
+        VerifyError::UnknownKid | VerifyError::UnknownKeyAlgorithm | VerifyError::InvalidDecodingKey => {
+            (StatusCode::UNAUTHORIZED, "unknown signing key")
+        }
+        VerifyError::InvalidSignature => (StatusCode::UNAUTHORIZED, "invalid token signature"),
+        VerifyError::Expired => (StatusCode::UNAUTHORIZED, "token expired"),
+        VerifyError::InvalidIssuer | VerifyError::InvalidAudience => {
+            (StatusCode::FORBIDDEN, "token not valid for this service")
+        }
+        VerifyError::InvalidSubject | VerifyError::InvalidAuthTime => {
+            (StatusCode::UNAUTHORIZED, "invalid token claims")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_error_to_rejection_covers_every_variant() {
+        let cases = [
+            (VerifyError::MissingKid, StatusCode::UNAUTHORIZED),
+            (VerifyError::MalformedToken, StatusCode::UNAUTHORIZED),
+            (VerifyError::UnknownKid, StatusCode::UNAUTHORIZED),
+            (VerifyError::UnknownKeyAlgorithm, StatusCode::UNAUTHORIZED),
+            (VerifyError::InvalidDecodingKey, StatusCode::UNAUTHORIZED),
+            (VerifyError::InvalidSignature, StatusCode::UNAUTHORIZED),
+            (VerifyError::Expired, StatusCode::UNAUTHORIZED),
+            (VerifyError::InvalidIssuer, StatusCode::FORBIDDEN),
+            (VerifyError::InvalidAudience, StatusCode::FORBIDDEN),
+            (VerifyError::InvalidSubject, StatusCode::UNAUTHORIZED),
+            (VerifyError::InvalidAuthTime, StatusCode::UNAUTHORIZED),
+        ];
+
+        for (err, expected_status) in cases {
+            let (status, _) = verify_error_to_rejection(err);
+            assert_eq!(status, expected_status);
+        }
+    }
+}
@@ -12,12 +12,22 @@ pub(crate) struct KeyResponse {
 
 #[derive(Debug, Serialize, Deserialize, Eq, PartialEq, Clone)]
 pub(crate) struct Jwk {
-    pub(crate) e: String,
     pub(crate) alg: String,
     pub(crate) kty: String,
     pub(crate) kid: String,
-    pub(crate) n: String,
     pub(crate) r#use: String,
+    // RSA (kty: "RSA")
+    #[serde(default)]
+    pub(crate) e: Option<String>,
+    #[serde(default)]
+    pub(crate) n: Option<String>,
+    // EC (kty: "EC") and OKP/Ed25519 (kty: "OKP")
+    #[serde(default)]
+    pub(crate) crv: Option<String>,
+    #[serde(default)]
+    pub(crate) x: Option<String>,
+    #[serde(default)]
+    pub(crate) y: Option<String>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
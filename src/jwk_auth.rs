@@ -1,7 +1,8 @@
 use crate::jwk::JwkFetcher;
-use crate::verifier::JwkVerifier;
+use crate::verifier::{JwkVerifier, VerifyError};
 use jsonwebtoken::TokenData;
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::Mutex;
@@ -10,6 +11,31 @@ const ISSUER_URL: &str = "https://securetoken.google.com/";
 const DEFAULT_PUBKEY_URL: &str =
     "https://www.googleapis.com/service_accounts/v1/jwk/securetoken@system.gserviceaccount.com";
 
+#[derive(Debug, Deserialize)]
+struct DiscoveryDocument {
+    issuer: String,
+    jwks_uri: String,
+}
+
+#[derive(Debug)]
+enum DiscoveryError {
+    RequestError(reqwest::Error),
+    ReponseBodyError(reqwest::Error),
+}
+
+async fn fetch_discovery_document(issuer_url: &str) -> Result<DiscoveryDocument, DiscoveryError> {
+    let discovery_url = format!(
+        "{}/.well-known/openid-configuration",
+        issuer_url.trim_end_matches('/')
+    );
+    reqwest::get(&discovery_url)
+        .await
+        .map_err(DiscoveryError::RequestError)?
+        .json::<DiscoveryDocument>()
+        .await
+        .map_err(DiscoveryError::ReponseBodyError)
+}
+
 #[derive(Clone)]
 pub struct JwkAuth {
     verifier: Arc<Mutex<JwkVerifier>>,
@@ -26,6 +52,42 @@ impl JwkAuth {
         let audience = project_id;
         let fetcher = JwkFetcher::new(pubkey_url);
 
+        Self::new_from_fetcher(fetcher, audience, issuer, true).await
+    }
+
+    /// Discovers an OIDC provider's `jwks_uri` and `issuer` from its
+    /// `<issuer_url>/.well-known/openid-configuration` document, instead of
+    /// relying on Firebase's hard-coded endpoints. Works for any
+    /// OIDC-compliant provider, including Firebase's session-cookie issuer.
+    pub async fn new_from_discovery(issuer_url: String, audience: String) -> JwkAuth {
+        let discovery = match fetch_discovery_document(&issuer_url).await {
+            Ok(discovery) => discovery,
+            Err(err) => {
+                panic!("Unable to fetch OIDC discovery document {:?}!", err)
+            }
+        };
+        let fetcher = JwkFetcher::new(discovery.jwks_uri);
+
+        Self::new_from_fetcher(fetcher, audience, discovery.issuer, false).await
+    }
+
+    /// Verifies against the Firebase Auth emulator's unsigned tokens instead
+    /// of production Firebase.
+    pub async fn new_emulated(project_id: String) -> JwkAuth {
+        let issuer = format!("{}{}", ISSUER_URL, project_id.clone());
+        let audience = project_id;
+
+        let verifier = Arc::new(Mutex::new(JwkVerifier::new_emulated(audience, issuer)));
+
+        JwkAuth { verifier }
+    }
+
+    async fn new_from_fetcher(
+        fetcher: JwkFetcher,
+        audience: String,
+        issuer: String,
+        validate_firebase_claims: bool,
+    ) -> JwkAuth {
         let jwk_key_result = fetcher.fetch_keys().await;
         let jwk_keys = match jwk_key_result {
             Ok(keys) => keys,
@@ -34,18 +96,21 @@ impl JwkAuth {
             }
         };
 
-        let verifier = Arc::new(Mutex::new(JwkVerifier::new(
-            jwk_keys.keys,
-            audience,
-            issuer,
-        )));
+        let verifier = Arc::new(Mutex::new(if validate_firebase_claims {
+            JwkVerifier::new(jwk_keys.keys, audience, issuer)
+        } else {
+            JwkVerifier::new_oidc(jwk_keys.keys, audience, issuer)
+        }));
 
         Self::start_periodic_key_update(fetcher, verifier.clone());
 
         JwkAuth { verifier }
     }
 
-    pub async fn verify<'a, C: DeserializeOwned + 'a>(&self, token: &str) -> Option<TokenData<C>> {
+    pub async fn verify<'a, C: DeserializeOwned + 'a>(
+        &self,
+        token: &str,
+    ) -> Result<TokenData<C>, VerifyError> {
         let verifier = self.verifier.lock().await;
         verifier.verify(token)
     }
@@ -101,4 +166,31 @@ mod tests {
             })
         );
     }
+
+    #[tokio::test]
+    async fn test_jwk_auth_new_from_discovery() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, ResponseTemplate};
+
+        let keys = get_test_keys();
+        let mock_server = get_mock_server().await;
+        let issuer = "https://example-issuer.test".to_string();
+        let audience = "some-audience".to_string();
+
+        Mock::given(method("GET"))
+            .and(path("/.well-known/openid-configuration"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "issuer": issuer,
+                "jwks_uri": get_mock_url(&mock_server),
+            })))
+            .mount(&mock_server)
+            .await;
+
+        let jwk_auth = JwkAuth::new_from_discovery(mock_server.uri(), audience.clone()).await;
+        let verifier = jwk_auth.verifier.lock().await;
+
+        assert_eq!(verifier.get_key("kid-0"), Some(&keys[0]));
+        assert_eq!(verifier.get_key("kid-1"), Some(&keys[1]));
+        assert_eq!(verifier.get_config(), Some(&JwkConfig { audience, issuer }));
+    }
 }
@@ -1,10 +1,16 @@
+#[cfg(feature = "axum")]
+mod extractor;
 mod header_parser;
 mod jwk;
 mod jwk_auth;
+mod signer;
 mod verifier;
 
+#[cfg(feature = "axum")]
+pub use extractor::Claims;
 pub use jwk_auth::JwkAuth;
-pub use verifier::BasicClaims;
+pub use signer::{CustomTokenSigner, ServiceAccountKey, SignerError};
+pub use verifier::{BasicClaims, VerifyError};
 
 #[cfg(test)]
 mod tests {
@@ -19,18 +25,24 @@ mod tests {
             Jwk {
                 alg: "RS256".to_string(),
                 kid: "kid-0".to_string(),
-                e: "AQAB".to_string(),
-                n: "n-string".to_string(),
+                e: Some("AQAB".to_string()),
+                n: Some("n-string".to_string()),
                 kty: "RSA".to_string(),
                 r#use: "sig".to_string(),
+                crv: None,
+                x: None,
+                y: None,
             },
             Jwk {
-                e: "AQAB".to_string(),
+                e: Some("AQAB".to_string()),
                 kty: "RSA".to_string(),
-                n: "n-string".to_string(),
+                n: Some("n-string".to_string()),
                 kid: "kid-1".to_string(),
                 alg: "RS256".to_string(),
                 r#use: "sig".to_string(),
+                crv: None,
+                x: None,
+                y: None,
             },
         ]
     }